@@ -0,0 +1,130 @@
+use std::{borrow::Cow, str::FromStr};
+
+use bigdecimal::BigDecimal;
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef, Registry},
+    types::{ParseError, ParseFromJSON, ParseResult, ToJSON, Type},
+};
+
+/// A precision-preserving `Type` impl for [`bigdecimal::BigDecimal`].
+///
+/// serde_json coerces JSON number literals through `f64` by default, which
+/// silently loses precision for money amounts and large integer IDs. With
+/// serde_json's `arbitrary_precision` feature enabled, though, a
+/// `Value::Number` retains the exact textual token it was parsed from
+/// instead - so this impl reads that token straight into a `BigDecimal`
+/// rather than going through `f64`, and writes it back out the same way.
+/// `rust_decimal::Decimal` was considered, but its 96-bit mantissa tops out
+/// at `79228162514264337593543950335`, smaller than values like
+/// `123456789012345678901234567890` this is meant to carry exactly -
+/// `BigDecimal` has no such ceiling.
+///
+/// This module only compiles in when the crate's `arbitrary_precision`
+/// feature is enabled (see its declaration in `external/mod.rs`), so a build
+/// that hasn't wired that feature to `serde_json/arbitrary_precision` simply
+/// doesn't get `BigDecimal` support, rather than getting it with silently
+/// truncated precision.
+impl Type for BigDecimal {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "decimal".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            ty: "number",
+            format: Some("decimal"),
+            ..MetaSchema::ANY
+        }))
+    }
+
+    fn register(_registry: &mut Registry) {}
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl ParseFromJSON for BigDecimal {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.ok_or(ParseError::expected_input())?;
+        // A number literal's exact digits are preserved in `text` (given
+        // serde_json's `arbitrary_precision` feature); a string is accepted
+        // too, for clients that send decimals quoted to dodge other JSON
+        // parsers' own number-precision limits.
+        let text = match &value {
+            Value::Number(number) => number.to_string(),
+            Value::String(text) => text.clone(),
+            _ => return Err(ParseError::expected_type(value)),
+        };
+
+        BigDecimal::from_str(&text)
+            .map_err(|_| ParseError::custom(format!("invalid decimal number `{text}`")))
+    }
+}
+
+impl ToJSON for BigDecimal {
+    fn to_json(&self) -> Option<Value> {
+        // `to_plain_string` avoids scientific notation, which keeps the
+        // output valid JSON-number grammar for `Number::from_str`.
+        serde_json::Number::from_str(&self.to_plain_string())
+            .map(Value::Number)
+            .ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_exact_decimal_from_number_literal() {
+        let json_text = "123456789012345678901234567890.1234567890";
+        let value: Value = serde_json::from_str(json_text).unwrap();
+        let decimal = BigDecimal::parse_from_json(Some(value.clone())).unwrap();
+        assert_eq!(value, decimal.to_json().unwrap());
+    }
+
+    #[test]
+    fn large_integer_beyond_f64_precision_round_trips_exactly() {
+        // 30 significant digits, far past f64's ~15-17: if the
+        // `arbitrary_precision` feature weren't actually wired up, this
+        // value would already have been silently rounded through f64 by
+        // the time `serde_json::from_str` returns it, and this assertion
+        // would fail.
+        let json_text = "123456789012345678901234567890";
+        let value: Value = serde_json::from_str(json_text).unwrap();
+        let decimal = BigDecimal::parse_from_json(Some(value.clone())).unwrap();
+        assert_eq!(value, decimal.to_json().unwrap());
+    }
+
+    #[test]
+    fn parses_decimal_from_quoted_string() {
+        let decimal =
+            BigDecimal::parse_from_json(Some(serde_json::json!("1234.5"))).unwrap();
+        assert_eq!(decimal.to_json().unwrap(), serde_json::json!(1234.5));
+    }
+
+    #[test]
+    fn rejects_invalid_decimal_text() {
+        assert!(BigDecimal::parse_from_json(Some(serde_json::json!("not-a-number"))).is_err())
+    }
+
+    #[test]
+    fn rejects_non_number_non_string() {
+        assert!(BigDecimal::parse_from_json(Some(serde_json::json!([1, 2]))).is_err())
+    }
+}
@@ -0,0 +1,9 @@
+// See the doc comment on `decimal`'s `impl Type for BigDecimal` for why this
+// is gated on the `arbitrary_precision` feature.
+#[cfg(feature = "arbitrary_precision")]
+mod decimal;
+mod raw_json;
+mod result;
+
+pub use raw_json::RawJson;
+pub use result::Tagged;
@@ -0,0 +1,87 @@
+use std::borrow::Cow;
+
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef, Registry},
+    types::{ParseFromJSON, ParseResult, ToJSON, Type},
+};
+
+/// A JSON value that is captured verbatim instead of being validated against
+/// a concrete schema.
+///
+/// Borrowed from serde_json's `RawValue`, `RawJson` lets proxy or forwarding
+/// endpoints carry an opaque payload through the API surface - alongside, for
+/// example, a typed [`Result<T, E>`](Result) field - without paying for, or
+/// being constrained by, deserializing it into a concrete type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawJson(pub Value);
+
+impl Type for RawJson {
+    // `parse_from_json` treats a missing value as `Value::Null` rather than
+    // erroring, so the generated schema must not claim this field is
+    // required - otherwise a server that happily accepts the field's
+    // complete omission would disagree with its own advertised schema.
+    const IS_REQUIRED: bool = false;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "raw_json".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        // `RawJson` captures whatever JSON value it's given verbatim -
+        // object, array, string, number, or null - so no fixed `ty` is
+        // advertised here; pinning this to `"object"` would make a
+        // non-object payload violate its own generated schema.
+        MetaSchemaRef::Inline(Box::new(MetaSchema::ANY))
+    }
+
+    fn register(_registry: &mut Registry) {}
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl ParseFromJSON for RawJson {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        Ok(RawJson(value.unwrap_or(Value::Null)))
+    }
+}
+
+impl ToJSON for RawJson {
+    fn to_json(&self) -> Option<Value> {
+        Some(self.0.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_arbitrary_json_verbatim() {
+        let value = serde_json::json!({
+            "nested": [1, "two", { "three": 3.0 }],
+        });
+
+        let raw = RawJson::parse_from_json(Some(value.clone())).unwrap();
+        assert_eq!(value, raw.to_json().unwrap());
+    }
+
+    #[test]
+    fn treats_missing_value_as_null() {
+        let raw = RawJson::parse_from_json(None).unwrap();
+        assert_eq!(Value::Null, raw.to_json().unwrap());
+    }
+}
@@ -4,7 +4,10 @@ use serde_json::Value;
 
 use crate::{
     registry::{MetaDiscriminatorObject, MetaSchema, MetaSchemaRef, Registry},
-    types::{ParseError, ParseFromJSON, ParseResult, ToJSON, Type},
+    types::{
+        path::{finalize_message, ParseErrorPathExt},
+        ParseError, ParseFromJSON, ParseResult, ToJSON, Type,
+    },
 };
 
 impl<T: Type, E: Type> Type for Result<T, E> {
@@ -65,11 +68,11 @@ impl<T: ParseFromJSON, E: ParseFromJSON> ParseFromJSON for Result<T, E> {
         if let Some(ok_value) = json_map.remove("ok") {
             T::parse_from_json(Some(ok_value))
                 .map(Result::Ok)
-                .map_err(|error| ParseError::from(error.into_message()))
+                .map_err(|error| error.push_front("ok"))
         } else if let Some(err_value) = json_map.remove("err") {
             E::parse_from_json(Some(err_value))
                 .map(Result::Err)
-                .map_err(|error| ParseError::from(error.into_message()))
+                .map_err(|error| error.push_front("err"))
         } else {
             Err(ParseError::expected_type(value))
         }
@@ -85,6 +88,196 @@ impl<T: ToJSON, E: ToJSON> ToJSON for Result<T, E> {
     }
 }
 
+/// The discriminator property name used by [`Tagged`] to distinguish its
+/// variants.
+const TAG_PROPERTY: &str = "type";
+
+/// The discriminator value written for the [`Ok`] variant of a [`Tagged`].
+const TAG_OK: &str = "ok";
+
+/// The discriminator value written for the [`Err`] variant of a [`Tagged`].
+const TAG_ERR: &str = "err";
+
+/// An internally-tagged alternative to [`Result<T, E>`].
+///
+/// Where `Result<T, E>` distinguishes its variants structurally (by the
+/// presence of an `"ok"` or `"err"` property), `Tagged<T, E>` writes an
+/// explicit `"type"` property alongside the flattened fields of the active
+/// variant, and the generated schema carries a real OpenAPI `discriminator`
+/// object so clients can dispatch on it directly instead of probing.
+///
+/// `T` and `E` are expected to serialize to a JSON object with no `"type"`
+/// field of its own, so the discriminator can be merged directly into it -
+/// this mirrors serde's own internally-tagged enum representation, which has
+/// the same preference. Nothing about `ToJSON` lets this be required at
+/// compile time, though, and a variant is free to be a scalar, an array, or
+/// an object that already uses `"type"` for something else. Rather than
+/// panic on those inputs, `to_json` falls back to wrapping the variant under
+/// a `"value"` property instead (`{"type": .., "value": ..}`) - the same
+/// shape [`parse_variant`] already accepts leniently when parsing, so it
+/// round-trips - and `schema_ref()` lists it as an additional `any_of`
+/// alternative alongside the flattened shape.
+pub struct Tagged<T, E>(pub Result<T, E>);
+
+fn tag_schema_ref() -> MetaSchemaRef {
+    MetaSchemaRef::Inline(Box::new(MetaSchema {
+        ty: "string",
+        ..MetaSchema::ANY
+    }))
+}
+
+/// The `{"type": .., "value": ..}` fallback shape described on [`Tagged`],
+/// as a schema alternative for the case `variant_schema_ref`'s flattening
+/// can't apply to.
+fn wrapped_variant_schema_ref<V: Type>() -> MetaSchemaRef {
+    MetaSchemaRef::Inline(Box::new(MetaSchema {
+        properties: vec![(TAG_PROPERTY, tag_schema_ref()), ("value", V::schema_ref())],
+        ..MetaSchema::ANY
+    }))
+}
+
+fn variant_schema_ref<V: Type>() -> MetaSchemaRef {
+    V::schema_ref().merge(MetaSchema {
+        properties: vec![(TAG_PROPERTY, tag_schema_ref())],
+        ..MetaSchema::ANY
+    })
+}
+
+impl<T: Type, E: Type> Type for Tagged<T, E> {
+    const IS_REQUIRED: bool = false;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        format!("tagged<{}, {}>", T::name(), E::name()).into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            rust_typename: Some("union::tagged::Result"),
+            ty: "object",
+            discriminator: Some(MetaDiscriminatorObject {
+                property_name: TAG_PROPERTY,
+                mapping: vec![
+                    (TAG_OK.to_string(), T::name().into_owned()),
+                    (TAG_ERR.to_string(), E::name().into_owned()),
+                ],
+            }),
+            any_of: vec![
+                variant_schema_ref::<T>(),
+                wrapped_variant_schema_ref::<T>(),
+                variant_schema_ref::<E>(),
+                wrapped_variant_schema_ref::<E>(),
+            ],
+            ..MetaSchema::ANY
+        }))
+    }
+
+    fn register(registry: &mut Registry) {
+        T::register(registry);
+        E::register(registry);
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl<T: ParseFromJSON, E: ParseFromJSON> ParseFromJSON for Tagged<T, E> {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.ok_or(ParseError::expected_input())?;
+        let mut json_map = match value {
+            Value::Object(map) => map,
+            _ => return Err(ParseError::custom("expected an object")),
+        };
+
+        let tag = json_map
+            .remove(TAG_PROPERTY)
+            .ok_or_else(|| ParseError::custom(format!("missing discriminator property `{TAG_PROPERTY}`")))?;
+        let tag = tag
+            .as_str()
+            .ok_or_else(|| ParseError::custom(format!("discriminator property `{TAG_PROPERTY}` must be a string")))?;
+
+        let payload = Value::Object(json_map);
+        match tag {
+            TAG_OK => parse_variant::<T>(payload)
+                .map(Result::Ok)
+                .map(Tagged)
+                .map_err(|error| error.push_front("ok")),
+            TAG_ERR => parse_variant::<E>(payload)
+                .map(Result::Err)
+                .map(Tagged)
+                .map_err(|error| error.push_front("err")),
+            other => Err(ParseError::custom(format!(
+                "unknown discriminator value `{other}`, expected `{TAG_OK}` or `{TAG_ERR}`"
+            ))),
+        }
+    }
+}
+
+/// Parses a `Tagged` variant's payload, after the discriminator property has
+/// been stripped off.
+///
+/// `Tagged::to_json` prefers flattening the variant directly into the wire
+/// object, but falls back to a `{"value": ...}` wrapper when it can't (see
+/// [`Tagged`]'s docs); this leniently accepts that wrapper shape too, for
+/// payloads from a generator that always represents it that way. That shape
+/// can look identical to a flattened object whose only field happens to be
+/// named `value`, so rather than guess from the shape alone, try the
+/// flattened interpretation first and only fall back to unwrapping `"value"`
+/// if that fails - a genuine `{value: ...}`-shaped `T` round-trips
+/// correctly, since the direct parse succeeds before the fallback is ever
+/// considered.
+fn parse_variant<V: ParseFromJSON>(payload: Value) -> ParseResult<V> {
+    let is_value_wrapper_shape =
+        matches!(&payload, Value::Object(map) if map.len() == 1 && map.contains_key("value"));
+    if !is_value_wrapper_shape {
+        return V::parse_from_json(Some(payload));
+    }
+
+    match V::parse_from_json(Some(payload.clone())) {
+        Ok(value) => Ok(value),
+        Err(_) => {
+            let Value::Object(mut map) = payload else {
+                unreachable!("is_value_wrapper_shape guarantees an object")
+            };
+            V::parse_from_json(map.remove("value"))
+        }
+    }
+}
+
+impl<T: ToJSON, E: ToJSON> ToJSON for Tagged<T, E> {
+    fn to_json(&self) -> Option<Value> {
+        let (tag, value) = match &self.0 {
+            Ok(t) => (TAG_OK, t.to_json()?),
+            Err(e) => (TAG_ERR, e.to_json()?),
+        };
+
+        // The preferred shape, flattening the variant's own fields
+        // alongside the discriminator, only applies when the variant is an
+        // object that doesn't already have a `"type"` field of its own; see
+        // `Tagged`'s docs for the `"value"`-wrapped fallback used otherwise.
+        let mut map = match value {
+            Value::Object(map) if !map.contains_key(TAG_PROPERTY) => map,
+            other => {
+                let mut wrapper = serde_json::Map::new();
+                wrapper.insert("value".to_string(), other);
+                wrapper
+            }
+        };
+        map.insert(TAG_PROPERTY.to_string(), Value::String(tag.to_string()));
+        Some(Value::Object(map))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -134,4 +327,286 @@ mod tests {
             Result::<usize, String>::parse_from_json(Some(err_json())).unwrap()
         )
     }
+
+    // `Tagged` prefers object-shaped variants (see its doc comment), so its
+    // tests below use these small object-shaped stand-ins instead of the
+    // scalar `ok_mock`/`err_mock` used for the plain `Result` above.
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct Message(String);
+
+    impl Type for Message {
+        const IS_REQUIRED: bool = true;
+        type RawValueType = Self;
+        type RawElementValueType = Self;
+
+        fn name() -> Cow<'static, str> {
+            "message".into()
+        }
+
+        fn schema_ref() -> MetaSchemaRef {
+            MetaSchemaRef::Inline(Box::new(MetaSchema::ANY))
+        }
+
+        fn register(_registry: &mut Registry) {}
+
+        fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+            Some(self)
+        }
+
+        fn raw_element_iter<'a>(
+            &'a self,
+        ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+            Box::new(self.as_raw_value().into_iter())
+        }
+    }
+
+    impl ParseFromJSON for Message {
+        fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+            let value = value.ok_or(ParseError::expected_input())?;
+            let message = value
+                .as_object()
+                .and_then(|map| map.get("message"))
+                .and_then(Value::as_str)
+                .ok_or_else(|| ParseError::custom("expected an object with a `message` field"))?;
+            Ok(Message(message.to_string()))
+        }
+    }
+
+    impl ToJSON for Message {
+        fn to_json(&self) -> Option<Value> {
+            Some(serde_json::json!({ "message": self.0 }))
+        }
+    }
+
+    fn tagged_ok_mock() -> Tagged<ValueField, Message> {
+        Tagged(Ok(ValueField { value: 10 }))
+    }
+
+    fn tagged_ok_json() -> serde_json::Value {
+        serde_json::json!({"type": "ok", "value": 10})
+    }
+
+    fn tagged_err_mock() -> Tagged<ValueField, Message> {
+        Tagged(Err(Message("invalid".to_string())))
+    }
+
+    fn tagged_err_json() -> serde_json::Value {
+        serde_json::json!({"type": "err", "message": "invalid"})
+    }
+
+    #[test]
+    fn serializes_tagged_ok_to_json() {
+        assert_eq!(tagged_ok_json(), tagged_ok_mock().to_json().unwrap())
+    }
+
+    #[test]
+    fn serializes_tagged_err_to_json() {
+        assert_eq!(tagged_err_json(), tagged_err_mock().to_json().unwrap())
+    }
+
+    #[test]
+    fn deserializes_tagged_json_ok() {
+        assert_eq!(
+            tagged_ok_mock().0,
+            Tagged::<ValueField, Message>::parse_from_json(Some(tagged_ok_json()))
+                .unwrap()
+                .0
+        )
+    }
+
+    #[test]
+    fn deserializes_tagged_json_err() {
+        assert_eq!(
+            tagged_err_mock().0,
+            Tagged::<ValueField, Message>::parse_from_json(Some(tagged_err_json()))
+                .unwrap()
+                .0
+        )
+    }
+
+    #[test]
+    fn rejects_missing_discriminator() {
+        assert!(
+            Tagged::<ValueField, Message>::parse_from_json(Some(serde_json::json!({}))).is_err()
+        )
+    }
+
+    #[test]
+    fn rejects_unknown_discriminator() {
+        assert!(Tagged::<ValueField, Message>::parse_from_json(Some(
+            serde_json::json!({"type": "maybe"})
+        ))
+        .is_err())
+    }
+
+    #[test]
+    fn nested_parse_failure_reports_path() {
+        let error =
+            Result::<usize, String>::parse_from_json(Some(serde_json::json!({"ok": "nope"})))
+                .unwrap_err();
+        assert!(finalize_message(&error.into_message()).starts_with("/ok: "));
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct ValueField {
+        value: usize,
+    }
+
+    impl Type for ValueField {
+        const IS_REQUIRED: bool = true;
+        type RawValueType = Self;
+        type RawElementValueType = Self;
+
+        fn name() -> Cow<'static, str> {
+            "value_field".into()
+        }
+
+        fn schema_ref() -> MetaSchemaRef {
+            MetaSchemaRef::Inline(Box::new(MetaSchema::ANY))
+        }
+
+        fn register(_registry: &mut Registry) {}
+
+        fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+            Some(self)
+        }
+
+        fn raw_element_iter<'a>(
+            &'a self,
+        ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+            Box::new(self.as_raw_value().into_iter())
+        }
+    }
+
+    impl ParseFromJSON for ValueField {
+        fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+            let value = value.ok_or(ParseError::expected_input())?;
+            let value = value
+                .as_object()
+                .and_then(|map| map.get("value"))
+                .and_then(Value::as_u64)
+                .ok_or_else(|| ParseError::custom("expected an object with a `value` field"))?;
+            Ok(ValueField {
+                value: value as usize,
+            })
+        }
+    }
+
+    impl ToJSON for ValueField {
+        fn to_json(&self) -> Option<Value> {
+            Some(serde_json::json!({ "value": self.value }))
+        }
+    }
+
+    #[test]
+    fn tagged_round_trips_object_whose_only_field_is_named_value() {
+        let tagged = Tagged::<ValueField, Message>(Ok(ValueField { value: 42 }));
+        let json = tagged.to_json().unwrap();
+        assert_eq!(json, serde_json::json!({"type": "ok", "value": 42}));
+
+        let parsed = Tagged::<ValueField, Message>::parse_from_json(Some(json))
+            .unwrap()
+            .0;
+        assert_eq!(parsed, Ok(ValueField { value: 42 }));
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct HasTypeField(String);
+
+    impl Type for HasTypeField {
+        const IS_REQUIRED: bool = true;
+        type RawValueType = Self;
+        type RawElementValueType = Self;
+
+        fn name() -> Cow<'static, str> {
+            "has_type_field".into()
+        }
+
+        fn schema_ref() -> MetaSchemaRef {
+            MetaSchemaRef::Inline(Box::new(MetaSchema::ANY))
+        }
+
+        fn register(_registry: &mut Registry) {}
+
+        fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+            Some(self)
+        }
+
+        fn raw_element_iter<'a>(
+            &'a self,
+        ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+            Box::new(self.as_raw_value().into_iter())
+        }
+    }
+
+    impl ParseFromJSON for HasTypeField {
+        fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+            let value = value.ok_or(ParseError::expected_input())?;
+            let kind = value
+                .as_object()
+                .and_then(|map| map.get("type"))
+                .and_then(Value::as_str)
+                .ok_or_else(|| ParseError::custom("expected an object with a `type` field"))?;
+            Ok(HasTypeField(kind.to_string()))
+        }
+    }
+
+    impl ToJSON for HasTypeField {
+        fn to_json(&self) -> Option<Value> {
+            Some(serde_json::json!({ "type": self.0 }))
+        }
+    }
+
+    #[test]
+    fn tagged_wraps_variant_with_its_own_type_field_instead_of_flattening() {
+        let tagged = Tagged::<HasTypeField, Message>(Ok(HasTypeField("custom".to_string())));
+        let json = tagged.to_json().unwrap();
+        assert_eq!(json, serde_json::json!({"type": "ok", "value": {"type": "custom"}}));
+
+        let parsed = Tagged::<HasTypeField, Message>::parse_from_json(Some(json))
+            .unwrap()
+            .0;
+        assert_eq!(parsed, Ok(HasTypeField("custom".to_string())));
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct ScalarVariant(usize);
+
+    impl ParseFromJSON for ScalarVariant {
+        fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+            let value = value.ok_or(ParseError::expected_input())?;
+            value
+                .as_u64()
+                .map(|n| ScalarVariant(n as usize))
+                .ok_or_else(|| ParseError::custom("expected an integer"))
+        }
+    }
+
+    impl ToJSON for ScalarVariant {
+        fn to_json(&self) -> Option<Value> {
+            Some(serde_json::json!(self.0))
+        }
+    }
+
+    #[test]
+    fn tagged_wraps_non_object_variant_instead_of_panicking() {
+        let tagged = Tagged::<ScalarVariant, Message>(Ok(ScalarVariant(42)));
+        let json = tagged.to_json().unwrap();
+        assert_eq!(json, serde_json::json!({"type": "ok", "value": 42}));
+
+        let parsed = Tagged::<ScalarVariant, Message>::parse_from_json(Some(json))
+            .unwrap()
+            .0;
+        assert_eq!(parsed, Ok(ScalarVariant(42)));
+    }
+
+    #[test]
+    fn nested_tagged_parse_failure_reports_path() {
+        let error = Tagged::<ValueField, Message>::parse_from_json(Some(
+            serde_json::json!({"type": "ok", "value": "nope"}),
+        ))
+        .unwrap_err();
+        assert!(finalize_message(&error.into_message()).starts_with("/ok: "));
+    }
 }
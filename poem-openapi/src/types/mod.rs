@@ -0,0 +1,5 @@
+mod external;
+mod path;
+
+pub use external::{RawJson, Tagged};
+pub use path::{finalize_message, path_segments, ParseErrorPathExt, PathSegment};
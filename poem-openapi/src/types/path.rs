@@ -0,0 +1,261 @@
+use std::fmt::{self, Display};
+
+use crate::types::ParseError;
+
+/// A single step in the location of a [`ParseError`], used to build up a
+/// JSON-pointer-like path as the error propagates out of nested combinators.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathSegment {
+    /// An object property.
+    Key(String),
+    /// An array index.
+    Index(usize),
+}
+
+impl Display for PathSegment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathSegment::Key(key) => write!(f, "{key}"),
+            PathSegment::Index(index) => write!(f, "{index}"),
+        }
+    }
+}
+
+impl From<&str> for PathSegment {
+    fn from(key: &str) -> Self {
+        PathSegment::Key(key.to_string())
+    }
+}
+
+impl From<String> for PathSegment {
+    fn from(key: String) -> Self {
+        PathSegment::Key(key)
+    }
+}
+
+impl From<usize> for PathSegment {
+    fn from(index: usize) -> Self {
+        PathSegment::Index(index)
+    }
+}
+
+/// Extends [`ParseError`] so a combinator that descends into a child value
+/// can record where, not just why, parsing failed.
+///
+/// A combinator that unwraps a nested [`ParseFromJSON`](crate::types::ParseFromJSON)
+/// call and wants its location reflected in the error can push its own
+/// segment onto the error on the way out, so that an error built from
+/// several nested `push_front` calls ends up carrying the full location as a
+/// JSON pointer (e.g. `/err/0/name: expected integer`). [`Result`]'s impl
+/// does this for its `"ok"`/`"err"` branch; this is opt-in per combinator,
+/// not automatic for every impl in the crate.
+///
+/// [`ParseError`] doesn't carry the path as a real field - it's an opaque
+/// type from outside this module, exposing only `custom`/`expected_input`/
+/// `expected_type`/`into_message`, with no constructor that takes a
+/// structured location. Until that changes, `push_front` marks the message
+/// it produces with a private [`PATH_MARKER`] so a later `push_front` can
+/// tell it apart from an ordinary leaf message (see [`split_path`]), and
+/// exposes the marked-up state through two public functions built on top of
+/// it instead: [`finalize_message`] strips the marker for final display, and
+/// [`path_segments`] recovers the structured location for a consumer that
+/// wants to introspect it rather than just display it. Whatever renders a
+/// `ParseError`'s message for final display (e.g. turning it into an HTTP
+/// error response) must run it through [`finalize_message`] first to strip
+/// that marker - `push_front` itself has no way to know whether the call
+/// it's part of is the last one in a chain of nested combinators.
+pub trait ParseErrorPathExt {
+    /// Prepends `segment` to this error's location.
+    fn push_front(self, segment: impl Into<PathSegment>) -> Self;
+}
+
+impl<T> ParseErrorPathExt for ParseError<T> {
+    fn push_front(self, segment: impl Into<PathSegment>) -> Self {
+        let (mut segments, message) = split_path(&self.into_message());
+        segments.insert(0, segment.into());
+        ParseError::custom(render_path(&segments, &message))
+    }
+}
+
+/// Prefixes a [`render_path`] message so [`split_path`] can tell it apart
+/// from an ordinary leaf message, even one that happens to look like a
+/// rendered path (e.g. `"/etc/passwd: permission denied"`, which has both the
+/// leading `/` and a `": "` separator without being one). A private-use-area
+/// code point is never produced by ordinary text, so unlike matching on `/`
+/// and `": "` alone, a leaf message can't be mistaken for one - only a
+/// message this module itself produced carries the marker.
+const PATH_MARKER: char = '\u{e000}';
+
+/// Returns the structural segments a message built by
+/// [`push_front`](ParseErrorPathExt::push_front) carries, letting external
+/// consumers introspect a [`ParseError`]'s location instead of only ever
+/// seeing it pre-rendered into text. Empty if the message was never pathed.
+/// A segment written as a plain decimal number comes back as
+/// [`PathSegment::Index`], so a map key such as `"0"` is reported as an index.
+pub fn path_segments(message: &str) -> Vec<PathSegment> {
+    split_path(message).0
+}
+
+/// Splits a message previously produced by [`render_path`] back into its
+/// structural segments and the original leaf message.
+///
+/// A message is only treated as already carrying a path if it starts with
+/// [`PATH_MARKER`]; anything else - including a leaf message that merely
+/// resembles the rendered shape - is passed through untouched as a fresh
+/// leaf message instead of being (mis)parsed into segments.
+///
+/// Each segment is escaped with [`escape_segment`]/[`unescape_segment`]
+/// before it's joined into the path, so a segment that itself contains `/`
+/// or `:` (e.g. a map key `"a/b"` or `"x: y"`) round-trips as the single
+/// segment it is, rather than being torn apart by the `/` join or the `": "`
+/// message separator.
+fn split_path(message: &str) -> (Vec<PathSegment>, String) {
+    let no_path = || (Vec::new(), message.to_string());
+
+    let Some(rest) = message.strip_prefix(PATH_MARKER) else {
+        return no_path();
+    };
+    let Some(rest) = rest.strip_prefix('/') else {
+        return no_path();
+    };
+    let Some((path, tail)) = rest.split_once(": ") else {
+        return no_path();
+    };
+
+    let segments = path
+        .split('/')
+        .map(|segment| match segment.parse::<usize>() {
+            // Only the exact text an `Index` renders as is read back as one,
+            // so re-rendering the segments never changes the message.
+            Ok(index) if index.to_string() == segment => PathSegment::Index(index),
+            _ => PathSegment::from(unescape_segment(segment)),
+        })
+        .collect();
+    (segments, tail.to_string())
+}
+
+fn render_path(segments: &[PathSegment], message: &str) -> String {
+    let path = segments
+        .iter()
+        .map(|segment| escape_segment(&segment.to_string()))
+        .collect::<Vec<_>>()
+        .join("/");
+    format!("{PATH_MARKER}/{path}: {message}")
+}
+
+/// Strips the [`PATH_MARKER`] a message built by [`push_front`](ParseErrorPathExt::push_front)
+/// still carries, producing the clean `/segment/segment: message` text meant
+/// for final display. A message that was never pathed (no marker present) is
+/// returned unchanged.
+///
+/// This is `pub`, not `pub(crate)`, specifically so whatever turns a
+/// [`ParseError`] into user-facing text - an HTTP error response body, a CLI
+/// diagnostic, anything outside this crate - can depend on it directly; see
+/// [`ParseErrorPathExt`]'s docs for why that call is required.
+pub fn finalize_message(message: &str) -> &str {
+    message.strip_prefix(PATH_MARKER).unwrap_or(message)
+}
+
+/// Escapes `~`, `/`, and `:` in a single rendered segment, RFC-6901-pointer
+/// style, so it can be joined with other segments using `/` and followed by
+/// `: ` without the join or the message separator misreading the segment's
+/// own content as structure. `~` must be escaped first so the markers this
+/// introduces (`~0`/`~1`/`~2`) aren't themselves mistaken for escapes of the
+/// segment's original content.
+fn escape_segment(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1").replace(':', "~2")
+}
+
+/// Reverses [`escape_segment`]; markers are unescaped in the opposite order
+/// they were introduced.
+fn unescape_segment(segment: &str) -> String {
+    segment
+        .replace("~2", ":")
+        .replace("~1", "/")
+        .replace("~0", "~")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_single_segment_as_json_pointer() {
+        let error = ParseError::<i32>::custom("expected integer").push_front("name");
+        assert_eq!(finalize_message(&error.into_message()), "/name: expected integer");
+    }
+
+    #[test]
+    fn accumulates_segments_innermost_first() {
+        let error = ParseError::<i32>::custom("expected integer")
+            .push_front("name")
+            .push_front(0usize)
+            .push_front("err");
+        assert_eq!(
+            finalize_message(&error.into_message()),
+            "/err/0/name: expected integer"
+        );
+    }
+
+    #[test]
+    fn leading_slash_in_leaf_message_is_not_mistaken_for_a_path() {
+        let error = ParseError::<i32>::custom("/tmp not writable").push_front("ok");
+        assert_eq!(
+            finalize_message(&error.into_message()),
+            "/ok: /tmp not writable"
+        );
+    }
+
+    #[test]
+    fn leaf_message_resembling_a_rendered_path_is_not_mistaken_for_one() {
+        // This is the collision a shape-only heuristic (matching solely on a
+        // leading `/` plus a `": "` separator, with no marker) can't tell
+        // apart from an already-pathed message: `path` reads as segments
+        // `["etc", "passwd"]` and `tail` as `"permission denied"` either way.
+        let error =
+            ParseError::<i32>::custom("/etc/passwd: permission denied").push_front("field");
+        assert_eq!(
+            finalize_message(&error.into_message()),
+            "/field: /etc/passwd: permission denied"
+        );
+    }
+
+    #[test]
+    fn segment_containing_a_slash_survives_further_pushes() {
+        let error = ParseError::<i32>::custom("expected integer")
+            .push_front("a/b")
+            .push_front("outer");
+        assert_eq!(
+            finalize_message(&error.into_message()),
+            "/outer/a~1b: expected integer"
+        );
+    }
+
+    #[test]
+    fn path_segments_recovers_structured_location() {
+        let error = ParseError::<i32>::custom("expected integer")
+            .push_front("name")
+            .push_front(0usize);
+        assert_eq!(
+            path_segments(&error.into_message()),
+            vec![PathSegment::from(0usize), PathSegment::from("name")]
+        );
+    }
+
+    #[test]
+    fn path_segments_is_empty_for_an_unpathed_leaf_message() {
+        let error = ParseError::<i32>::custom("expected integer");
+        assert_eq!(path_segments(&error.into_message()), Vec::new());
+    }
+
+    #[test]
+    fn segment_containing_a_colon_survives_further_pushes() {
+        let error = ParseError::<i32>::custom("expected integer")
+            .push_front("x: y")
+            .push_front("outer");
+        assert_eq!(
+            finalize_message(&error.into_message()),
+            "/outer/x~2 y: expected integer"
+        );
+    }
+}